@@ -1,4 +1,4 @@
-use crate::output::Output;
+use crate::{output::Output, timer::Timer};
 
 /// A [`Layer`](tracing_subscriber::Layer) implementation that displays all spans that own an event
 /// when it occurs.
@@ -13,8 +13,12 @@ use crate::output::Output;
 /// Fields of spans and events are printed inline, so that each line is a log entry. However,
 /// calling [`Builder::multiline`] prints each field in a separate line.
 ///
-/// By default, the timestamp is printed for each entry. However, it might be useful to omit it if,
-/// e.g., using `jornald`. That can be achieved by calling [`Builder::omit_timestamp`].
+/// By default, the timestamp is printed for each entry using a UTC [`Timer`]. It might be useful
+/// to omit it, e.g. when using `jornald`, by calling [`Builder::omit_timestamp`], or to swap it
+/// for another clock, e.g. local time, by calling [`Builder::with_timer`].
+///
+/// ANSI colors are suppressed automatically when the output is not a terminal, and can be
+/// disabled unconditionally by calling [`Builder::no_color`].
 ///
 /// # Examples
 ///
@@ -29,7 +33,14 @@ pub struct Layer<O: Output> {
     output: O,
     log_spans: bool,
     multiline: bool,
-    timestamp: bool,
+    timer: Option<Box<dyn Timer>>,
+    json: bool,
+    ansi: bool,
+    span_timings: bool,
+    file: bool,
+    line_number: bool,
+    thread_ids: bool,
+    thread_names: bool,
     last_span: std::sync::atomic::AtomicU64,
 }
 
@@ -45,8 +56,20 @@ impl<O: Output> Layer<O> {
     /// let subscriber = tracing_subscriber::registry().with(layer);
     /// tracing::subscriber::set_global_default(subscriber).unwrap();
     /// ```
-    pub fn builder(output: O) -> Builder<O, false, false, true> {
-        Builder(output)
+    pub fn builder(
+        output: O,
+    ) -> Builder<O, false, false, true, true, false, false, false, false, false> {
+        Builder(output, Box::new(crate::timer::Utc))
+    }
+
+    /// The source/thread annotations this layer was configured to append.
+    fn annotations(&self) -> Annotations {
+        Annotations {
+            file: self.file,
+            line_number: self.line_number,
+            thread_ids: self.thread_ids,
+            thread_names: self.thread_names,
+        }
     }
 }
 
@@ -61,53 +84,556 @@ impl<O: Output> Layer<O> {
 /// let subscriber = tracing_subscriber::registry().with(layer);
 /// tracing::subscriber::set_global_default(subscriber).unwrap();
 /// ```
-pub struct Builder<O: Output, const LOG_SPANS: bool, const MULTILINE: bool, const TIMESTAMP: bool>(
-    O,
-);
+pub struct Builder<
+    O: Output,
+    const LOG_SPANS: bool,
+    const MULTILINE: bool,
+    const TIMESTAMP: bool,
+    const ANSI: bool,
+    const SPAN_TIMINGS: bool,
+    const FILE: bool,
+    const LINE_NUMBER: bool,
+    const THREAD_IDS: bool,
+    const THREAD_NAMES: bool,
+>(O, Box<dyn Timer>);
 
-impl<O: Output, const MULTILINE: bool, const TIMESTAMP: bool>
-    Builder<O, false, MULTILINE, TIMESTAMP>
+impl<
+        O: Output,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        false,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
 {
     /// Log spans on every entry.
     ///
     /// If disabled, spans are only loged if an event occurs.
-    pub fn log_spans(self) -> Builder<O, true, MULTILINE, TIMESTAMP> {
-        Builder(self.0)
+    pub fn log_spans(
+        self,
+    ) -> Builder<
+        O,
+        true,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
     }
 }
 
-impl<O: Output, const LOG_SPANS: bool, const TIMESTAMP: bool>
-    Builder<O, LOG_SPANS, false, TIMESTAMP>
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        false,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
 {
     /// Split the log fields into multiple lines.
     ///
     /// This option can enable log forging by a malicious source.
-    pub fn multiline(self) -> Builder<O, LOG_SPANS, true, TIMESTAMP> {
-        Builder(self.0)
+    pub fn multiline(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        true,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
     }
 }
 
-impl<O: Output, const LOG_SPANS: bool, const MULTILINE: bool>
-    Builder<O, LOG_SPANS, MULTILINE, true>
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        true,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
 {
     /// Omit the timestamp in the log.
     ///
     /// Useful when using with, e.g., `journald` to avoid noise.
-    pub fn omit_timestamp(self) -> Builder<O, LOG_SPANS, MULTILINE, false> {
-        Builder(self.0)
+    pub fn omit_timestamp(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        false,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
+    }
+
+    /// Use a custom [`Timer`] to render the timestamp, instead of the default
+    /// [`Utc`](crate::timer::Utc) timer.
+    #[must_use]
+    pub fn with_timer(
+        self,
+        timer: impl Timer,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        true,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, Box::new(timer))
+    }
+}
+
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        true,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+{
+    /// Disable ANSI colors, keeping only indentation, `[id]` markers, level words and fields.
+    ///
+    /// Useful when the output is piped to a file or consumed by a program instead of a terminal.
+    pub fn no_color(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        false,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
+    }
+}
+
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        false,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+{
+    /// Print the busy/idle time accounting of a span when it closes.
+    ///
+    /// The closing line shows how long the span spent entered (`busy`) versus existing without
+    /// being entered (`idle`), e.g. `12.3ms busy, 4.1ms idle`. Entering a nested span does not
+    /// count as idle time for its parent, and the time between the span's last exit and its close
+    /// is not counted.
+    pub fn span_timings(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        true,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
+    }
+}
+
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        false,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+{
+    /// Append the source file of each event, e.g. `src/main.rs`.
+    pub fn with_file(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        true,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
+    }
+}
+
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        false,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+{
+    /// Append the source line of each event, e.g. `:42`.
+    ///
+    /// Has no effect unless [`with_file`](Self::with_file) is also called, mirroring
+    /// `tracing-subscriber`'s behavior of only ever printing a line number next to its file.
+    pub fn with_line_number(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        true,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
+    }
+}
+
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        false,
+        THREAD_NAMES,
+    >
+{
+    /// Append the id of the thread that emitted each event.
+    pub fn with_thread_ids(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        true,
+        THREAD_NAMES,
+    >
+    {
+        Builder(self.0, self.1)
     }
 }
 
-impl<O: Output, const LOG_SPANS: bool, const MULTILINE: bool, const TIMESTAMP: bool>
-    Builder<O, LOG_SPANS, MULTILINE, TIMESTAMP>
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        false,
+    >
 {
+    /// Append the name of the thread that emitted each event, falling back to its id if the
+    /// thread is unnamed.
+    pub fn with_thread_names(
+        self,
+    ) -> Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        true,
+    >
+    {
+        Builder(self.0, self.1)
+    }
+}
+
+impl<
+        O: Output,
+        const LOG_SPANS: bool,
+        const MULTILINE: bool,
+        const TIMESTAMP: bool,
+        const ANSI: bool,
+        const SPAN_TIMINGS: bool,
+        const FILE: bool,
+        const LINE_NUMBER: bool,
+        const THREAD_IDS: bool,
+        const THREAD_NAMES: bool,
+    > Builder<
+        O,
+        LOG_SPANS,
+        MULTILINE,
+        TIMESTAMP,
+        ANSI,
+        SPAN_TIMINGS,
+        FILE,
+        LINE_NUMBER,
+        THREAD_IDS,
+        THREAD_NAMES,
+    >
+{
+    /// Emit one JSON object per event instead of the ANSI tree.
+    ///
+    /// This supersedes [`multiline`](Self::multiline), [`no_color`](Self::no_color),
+    /// [`span_timings`](Self::span_timings) and the source/thread annotations, which have no
+    /// meaning for JSON output, as each event already carries its fields and span hierarchy as
+    /// structured data.
+    pub fn json(self) -> BuilderJson<O, LOG_SPANS, TIMESTAMP> {
+        BuilderJson(self.0, self.1)
+    }
+
     /// Constructs the final [`Layer`] instance with the options provided.
+    ///
+    /// If colors were not explicitly disabled with [`no_color`](Self::no_color), they are still
+    /// suppressed automatically when `output` is not a terminal.
     pub fn build(self) -> Layer<O> {
+        let ansi = ANSI && self.0.is_terminal();
+
         Layer {
             output: self.0,
             log_spans: LOG_SPANS,
             multiline: MULTILINE,
-            timestamp: TIMESTAMP,
+            timer: TIMESTAMP.then_some(self.1),
+            json: false,
+            ansi,
+            span_timings: SPAN_TIMINGS,
+            file: FILE,
+            line_number: LINE_NUMBER,
+            thread_ids: THREAD_IDS,
+            thread_names: THREAD_NAMES,
+            last_span: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+}
+
+/// The builder for a [`Layer`] in JSON output mode.
+///
+/// # Examples
+///
+/// ```
+/// # use treetrace::{layer::Layer, output::Stdout};
+/// # use tracing_subscriber::layer::SubscriberExt;
+/// let layer = Layer::builder(Stdout).json().log_spans().build();
+/// let subscriber = tracing_subscriber::registry().with(layer);
+/// tracing::subscriber::set_global_default(subscriber).unwrap();
+/// ```
+pub struct BuilderJson<O: Output, const LOG_SPANS: bool, const TIMESTAMP: bool>(O, Box<dyn Timer>);
+
+impl<O: Output, const TIMESTAMP: bool> BuilderJson<O, false, TIMESTAMP> {
+    /// Log spans on every entry.
+    ///
+    /// If disabled, spans are only loged if an event occurs.
+    pub fn log_spans(self) -> BuilderJson<O, true, TIMESTAMP> {
+        BuilderJson(self.0, self.1)
+    }
+}
+
+impl<O: Output, const LOG_SPANS: bool> BuilderJson<O, LOG_SPANS, true> {
+    /// Omit the timestamp in the log.
+    ///
+    /// Useful when using with, e.g., `journald` to avoid noise.
+    pub fn omit_timestamp(self) -> BuilderJson<O, LOG_SPANS, false> {
+        BuilderJson(self.0, self.1)
+    }
+
+    /// Use a custom [`Timer`] to render the timestamp, instead of the default
+    /// [`Utc`](crate::timer::Utc) timer.
+    #[must_use]
+    pub fn with_timer(self, timer: impl Timer) -> BuilderJson<O, LOG_SPANS, true> {
+        BuilderJson(self.0, Box::new(timer))
+    }
+}
+
+impl<O: Output, const LOG_SPANS: bool, const TIMESTAMP: bool> BuilderJson<O, LOG_SPANS, TIMESTAMP> {
+    /// Constructs the final [`Layer`] instance with the options provided.
+    pub fn build(self) -> Layer<O> {
+        Layer {
+            output: self.0,
+            log_spans: LOG_SPANS,
+            multiline: false,
+            timer: TIMESTAMP.then_some(self.1),
+            json: true,
+            ansi: false,
+            span_timings: false,
+            file: false,
+            line_number: false,
+            thread_ids: false,
+            thread_names: false,
             last_span: std::sync::atomic::AtomicU64::new(0),
         }
     }
@@ -115,13 +641,17 @@ impl<O: Output, const LOG_SPANS: bool, const MULTILINE: bool, const TIMESTAMP: b
 
 struct SpanInfo {
     id: u16,
-    date_time: Option<chrono::DateTime<chrono::Utc>>,
+    date_time: Option<String>,
     records: Vec<(&'static str, String)>,
     new: std::sync::atomic::AtomicBool,
+    created_at: std::time::Instant,
+    busy: std::sync::atomic::AtomicU64,
+    idle: std::sync::atomic::AtomicU64,
+    last_transition_nanos: std::sync::atomic::AtomicU64,
 }
 
 impl SpanInfo {
-    fn new(attrs: &tracing::span::Attributes<'_>, timestamp: bool) -> Self {
+    fn new(attrs: &tracing::span::Attributes<'_>, timer: Option<&dyn Timer>) -> Self {
         use rand::SeedableRng;
 
         struct Visistor(Vec<(&'static str, String)>);
@@ -137,10 +667,43 @@ impl SpanInfo {
 
         Self {
             id: rand::Rng::random(&mut rand::rngs::SmallRng::from_os_rng()),
-            date_time: timestamp.then_some(chrono::Utc::now()),
+            date_time: timer.map(render_timestamp),
             records: visitor.0,
             new: std::sync::atomic::AtomicBool::new(true),
+            created_at: std::time::Instant::now(),
+            busy: std::sync::atomic::AtomicU64::new(0),
+            idle: std::sync::atomic::AtomicU64::new(0),
+            last_transition_nanos: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    /// Merges `values` into the stored fields, updating any field already present in place and
+    /// appending the rest.
+    fn record(&mut self, values: &tracing::span::Record<'_>) {
+        struct Visistor<'a>(&'a mut Vec<(&'static str, String)>);
+
+        impl tracing_subscriber::field::Visit for Visistor<'_> {
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                let value = format!("{value:?}");
+                if let Some(existing) = self.0.iter_mut().find(|(name, _)| *name == field.name()) {
+                    existing.1 = value;
+                } else {
+                    self.0.push((field.name(), value));
+                }
+            }
         }
+
+        values.record(&mut Visistor(&mut self.records));
+    }
+
+    /// Records the time elapsed since the last enter/exit transition into `counter`, then resets
+    /// the transition point to now.
+    fn record_transition(&self, counter: &std::sync::atomic::AtomicU64) {
+        use std::sync::atomic::Ordering::Relaxed;
+
+        let now = u64::try_from(self.created_at.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let last = self.last_transition_nanos.swap(now, Relaxed);
+        counter.fetch_add(now.saturating_sub(last), Relaxed);
     }
 }
 
@@ -154,25 +717,44 @@ impl<O: Output> tracing_subscriber::Layer<tracing_subscriber::Registry> for Laye
         if let Some(span) = ctx.span(id) {
             if span.extensions().get::<SpanInfo>().is_none() {
                 span.extensions_mut()
-                    .insert(SpanInfo::new(attrs, self.timestamp));
+                    .insert(SpanInfo::new(attrs, self.timer.as_deref()));
             }
 
             if self.log_spans {
-                let mut stdout = self.output.lock();
+                let mut stdout = self.output.lock_for(span.metadata());
 
-                let depth = ctx.span_scope(id).map_or(0, std::iter::Iterator::count);
-                let last_span = self.last_span.load(std::sync::atomic::Ordering::Relaxed);
+                if self.json {
+                    print_span_json(&mut stdout, &span);
+                } else {
+                    let depth = ctx.span_scope(id).map_or(0, std::iter::Iterator::count);
+                    let last_span = self.last_span.load(std::sync::atomic::Ordering::Relaxed);
 
-                print_span(
-                    &mut stdout,
-                    last_span,
-                    depth.max(1) - 1,
-                    Some(span).as_ref(),
-                    self.multiline,
-                );
+                    print_span(
+                        &mut stdout,
+                        last_span,
+                        depth.max(1) - 1,
+                        Some(span).as_ref(),
+                        self.multiline,
+                        &Ansi(self.ansi),
+                        self.annotations(),
+                    );
 
-                self.last_span
-                    .store(id.into_u64(), std::sync::atomic::Ordering::Relaxed);
+                    self.last_span
+                        .store(id.into_u64(), std::sync::atomic::Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    fn on_record(
+        &self,
+        id: &tracing::span::Id,
+        values: &tracing::span::Record<'_>,
+        ctx: tracing_subscriber::layer::Context<'_, tracing_subscriber::Registry>,
+    ) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(info) = span.extensions_mut().get_mut::<SpanInfo>() {
+                info.record(values);
             }
         }
     }
@@ -182,18 +764,29 @@ impl<O: Output> tracing_subscriber::Layer<tracing_subscriber::Registry> for Laye
         event: &tracing::Event<'_>,
         ctx: tracing_subscriber::layer::Context<'_, tracing_subscriber::Registry>,
     ) {
-        let mut stdout = self.output.lock();
+        let mut stdout = self.output.lock_for(event.metadata());
 
-        let depth = ctx.event_scope(event).map_or(0, std::iter::Iterator::count);
         let current_span = ctx.current_span().id().and_then(|id| ctx.span(id));
+
+        if self.json {
+            print_event_json(&mut stdout, event, current_span.as_ref(), self.timer.as_deref());
+            return;
+        }
+
+        let depth = ctx.event_scope(event).map_or(0, std::iter::Iterator::count);
         let last_span = self.last_span.load(std::sync::atomic::Ordering::Relaxed);
 
+        let ansi = Ansi(self.ansi);
+        let annotations = self.annotations();
+
         print_span(
             &mut stdout,
             last_span,
             depth.max(1) - 1,
             current_span.as_ref(),
             self.multiline,
+            &ansi,
+            annotations,
         );
 
         self.last_span.store(
@@ -201,7 +794,39 @@ impl<O: Output> tracing_subscriber::Layer<tracing_subscriber::Registry> for Laye
             std::sync::atomic::Ordering::Relaxed,
         );
 
-        print_event(&mut stdout, event, depth, self.multiline, self.timestamp);
+        print_event(
+            &mut stdout,
+            event,
+            depth,
+            self.multiline,
+            self.timer.as_deref(),
+            &ansi,
+            annotations,
+        );
+    }
+
+    fn on_enter(
+        &self,
+        id: &tracing::span::Id,
+        ctx: tracing_subscriber::layer::Context<'_, tracing_subscriber::Registry>,
+    ) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(info) = span.extensions().get::<SpanInfo>() {
+                info.record_transition(&info.idle);
+            }
+        }
+    }
+
+    fn on_exit(
+        &self,
+        id: &tracing::span::Id,
+        ctx: tracing_subscriber::layer::Context<'_, tracing_subscriber::Registry>,
+    ) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(info) = span.extensions().get::<SpanInfo>() {
+                info.record_transition(&info.busy);
+            }
+        }
     }
 
     fn on_close(
@@ -209,9 +834,25 @@ impl<O: Output> tracing_subscriber::Layer<tracing_subscriber::Registry> for Laye
         id: tracing::span::Id,
         ctx: tracing_subscriber::layer::Context<'_, tracing_subscriber::Registry>,
     ) {
-        let lock = self.output.lock();
         let last_span = self.last_span.load(std::sync::atomic::Ordering::Relaxed);
 
+        if self.span_timings {
+            if let Some(span) = ctx.span(&id) {
+                if let Some(info) = span.extensions().get::<SpanInfo>() {
+                    let depth = ctx.span_scope(&id).map_or(0, std::iter::Iterator::count);
+                    let mut lock = self.output.lock_for(span.metadata());
+
+                    print_span_timing(
+                        &mut lock,
+                        depth.max(1) - 1,
+                        info.busy.load(std::sync::atomic::Ordering::Relaxed),
+                        info.idle.load(std::sync::atomic::Ordering::Relaxed),
+                        &Ansi(self.ansi),
+                    );
+                }
+            }
+        }
+
         if last_span == id.into_u64() {
             let prev_span = ctx
                 .span(&id)
@@ -220,7 +861,147 @@ impl<O: Output> tracing_subscriber::Layer<tracing_subscriber::Registry> for Laye
             self.last_span
                 .store(prev_span, std::sync::atomic::Ordering::Relaxed);
         }
-        drop(lock);
+    }
+}
+
+/// Renders a [`Timer`]'s output to a `String`, for storing alongside a span.
+fn render_timestamp(timer: &dyn Timer) -> String {
+    let mut buffer = Vec::new();
+    drop(timer.write_timestamp(&mut buffer));
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+/// Which source/thread annotations to append to a span or event line.
+#[derive(Debug, Clone, Copy, Default)]
+struct Annotations {
+    file: bool,
+    line_number: bool,
+    thread_ids: bool,
+    thread_names: bool,
+}
+
+/// Builds the labeled source/thread annotations to append to a span or event line, honoring
+/// which pieces are enabled.
+fn location_fields(
+    metadata: &tracing::Metadata<'_>,
+    annotations: Annotations,
+) -> Vec<(&'static str, String)> {
+    let mut fields = Vec::new();
+
+    if annotations.file {
+        if let Some(path) = metadata.file() {
+            fields.push((
+                "file",
+                if annotations.line_number {
+                    metadata
+                        .line()
+                        .map_or_else(|| path.to_owned(), |line| format!("{path}:{line}"))
+                } else {
+                    path.to_owned()
+                },
+            ));
+        }
+    }
+
+    if annotations.thread_ids {
+        fields.push(("thread_id", format!("{:?}", std::thread::current().id())));
+    }
+
+    if annotations.thread_names {
+        let thread = std::thread::current();
+        fields.push((
+            "thread_name",
+            thread
+                .name()
+                .map_or_else(|| format!("{:?}", thread.id()), std::borrow::ToOwned::to_owned),
+        ));
+    }
+
+    fields
+}
+
+/// Writes `fields` as trailing `key: value` annotations, matching the styling of a span's or
+/// event's own fields.
+fn print_location(
+    out: &mut impl std::io::Write,
+    fields: &[(&'static str, String)],
+    depth: usize,
+    multiline: bool,
+    ansi: &Ansi,
+) {
+    for (k, v) in fields {
+        if multiline {
+            drop(write!(
+                out,
+                "\n{indent:>0$}- {dim}{k}: {dim_reset}{v}",
+                depth + 22,
+                indent = "",
+                dim = ansi.dim(),
+                dim_reset = ansi.dim_reset(),
+            ));
+        } else {
+            drop(write!(
+                out,
+                " {dim}{k}: {dim_reset}{v}",
+                dim = ansi.dim(),
+                dim_reset = ansi.dim_reset(),
+            ));
+        }
+    }
+}
+
+/// ANSI escape codes used to render the tree, or empty strings when colors are disabled.
+struct Ansi(bool);
+
+impl Ansi {
+    fn code(&self, code: &'static str) -> &'static str {
+        if self.0 {
+            code
+        } else {
+            ""
+        }
+    }
+
+    fn reset(&self) -> &'static str {
+        self.code("\x1b[m")
+    }
+
+    fn dim(&self) -> &'static str {
+        self.code("\x1b[2m")
+    }
+
+    fn dim_reset(&self) -> &'static str {
+        self.code("\x1b[22m")
+    }
+
+    fn name(&self) -> &'static str {
+        self.code("\x1b[37m")
+    }
+
+    fn field(&self) -> &'static str {
+        self.code("\x1b[36m")
+    }
+
+    fn field_dim(&self) -> &'static str {
+        self.code("\x1b[36;2m")
+    }
+
+    fn stale(&self) -> &'static str {
+        self.code("\x1b[93m")
+    }
+
+    fn error(&self) -> &'static str {
+        self.code("\x1b[31m")
+    }
+
+    fn level(&self, level: tracing::Level) -> &'static str {
+        self.code(match level {
+            tracing::Level::TRACE => "\x1b[94m",
+            tracing::Level::DEBUG => "\x1b[34m",
+            tracing::Level::INFO => "\x1b[32m",
+            tracing::Level::WARN => "\x1b[33m",
+            tracing::Level::ERROR => "\x1b[31m",
+        })
     }
 }
 
@@ -230,84 +1011,93 @@ fn print_span(
     depth: usize,
     span: Option<&tracing_subscriber::registry::SpanRef<'_, tracing_subscriber::Registry>>,
     multiline: bool,
+    ansi: &Ansi,
+    annotations: Annotations,
 ) {
-    if let Some(span) = span {
-        if let Some(info) = span.extensions().get::<SpanInfo>() {
-            let new = info.new.swap(false, std::sync::atomic::Ordering::Relaxed);
-
-            if span.id().into_u64() != last_span || new {
-                print_span(
-                    out,
-                    last_span,
-                    depth.max(1) - 1,
-                    span.parent().as_ref(),
-                    multiline,
-                );
+    let Some(span) = span else {
+        return;
+    };
 
-                let path = span.metadata().target();
-                let name = span.name();
-                let div = if path.is_empty() || name.is_empty() {
-                    ""
-                } else {
-                    "::"
-                };
+    let extensions = span.extensions();
+    let Some(info) = extensions.get::<SpanInfo>() else {
+        drop(writeln!(
+            out,
+            "{error}Failed to read span info{reset}",
+            error = ansi.error(),
+            reset = ansi.reset(),
+        ));
+        return;
+    };
 
-                if let Some(date_time) = info.date_time {
-                    drop(write!(
-                        out,
-                        "[;2m[{timestamp}] ",
-                        timestamp = date_time.format("%Y-%m-%d %H:%M:%S"),
-                    ));
-                }
+    let new = info.new.swap(false, std::sync::atomic::Ordering::Relaxed);
 
-                drop(write!(
-                    out,
-                    "[m{indent:>0$}[m{path}{div}[37m{name}",
-                    depth * 2,
-                    indent = "",
-                ));
-
-                for (k, v) in &info.records {
-                    if *k == "message" {
-                        let space = if path.is_empty() && name.is_empty() {
-                            ""
-                        } else {
-                            " "
-                        };
-                        drop(write!(out, "[m{space}{v}"));
-                        break;
-                    }
-                }
+    if span.id().into_u64() == last_span && !new {
+        return;
+    }
 
-                drop(write!(
-                    out,
-                    "{arrow} [37m[{id:04x}][36m",
-                    arrow = if new { " " } else { "[93m^" },
-                    id = info.id,
-                ));
-
-                for (k, v) in &info.records {
-                    if *k == "message" {
-                        continue;
-                    }
-
-                    if multiline {
-                        drop(write!(
-                            out,
-                            "\n{indent:>0$}- [2m{k}: [22m{v}",
-                            depth * 2 + 22,
-                            indent = ""
-                        ));
-                    } else {
-                        drop(write!(out, " [2m{k}: [22m{v}"));
-                    }
-                }
-                drop(writeln!(out, "[m"));
-            }
-        } else {
-            drop(writeln!(out, "[31mFailed to read span info[m"));
+    print_span(
+        out,
+        last_span,
+        depth.max(1) - 1,
+        span.parent().as_ref(),
+        multiline,
+        ansi,
+        annotations,
+    );
+
+    let path = span.metadata().target();
+    let name = span.name();
+    let div = if path.is_empty() || name.is_empty() {
+        ""
+    } else {
+        "::"
+    };
+
+    if let Some(date_time) = &info.date_time {
+        drop(write!(out, "{dim}[{date_time}] ", dim = ansi.dim()));
+    }
+
+    drop(write!(
+        out,
+        "{reset}{indent:>0$}{reset}{path}{div}{name_color}{name}",
+        depth * 2,
+        indent = "",
+        reset = ansi.reset(),
+        name_color = ansi.name(),
+    ));
+
+    for (k, v) in &info.records {
+        if *k == "message" {
+            let space = if path.is_empty() && name.is_empty() { "" } else { " " };
+            drop(write!(out, "{reset}{space}{v}", reset = ansi.reset()));
+            break;
         }
     }
+
+    drop(write!(
+        out,
+        "{arrow} {name_color}[{id:04x}]{field_color}",
+        arrow = if new {
+            " ".to_owned()
+        } else {
+            format!("{}^", ansi.stale())
+        },
+        name_color = ansi.name(),
+        field_color = ansi.field(),
+        id = info.id,
+    ));
+
+    let mut fields: Vec<(&'static str, String)> = info
+        .records
+        .iter()
+        .filter(|(k, _)| *k != "message")
+        .map(|(k, v)| (*k, v.clone()))
+        .collect();
+    fields.extend(location_fields(span.metadata(), annotations));
+
+    print_location(out, &fields, depth * 2, multiline, ansi);
+
+    drop(writeln!(out, "{reset}", reset = ansi.reset()));
 }
 
 fn print_event(
@@ -315,7 +1105,9 @@ fn print_event(
     event: &tracing::Event<'_>,
     depth: usize,
     multiline: bool,
-    timestamp: bool,
+    timer: Option<&dyn Timer>,
+    ansi: &Ansi,
+    annotations: Annotations,
 ) {
     struct Messenger<'w, W>(&'w mut W);
     impl<W: std::io::Write> tracing_subscriber::field::Visit for Messenger<'_, W> {
@@ -326,7 +1118,7 @@ fn print_event(
         }
     }
 
-    struct Fielder<'w, W>(&'w mut W, Option<usize>);
+    struct Fielder<'w, W>(&'w mut W, Option<usize>, &'w Ansi);
     impl<W: std::io::Write> tracing_subscriber::field::Visit for Fielder<'_, W> {
         fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
             if field.name() != "message" {
@@ -334,49 +1126,333 @@ fn print_event(
                     let gap = depth + 22;
                     drop(write!(
                         self.0,
-                        "\n{indent:>gap$}- [36;2m{field}: [22m{value:?}",
-                        indent = ""
+                        "\n{indent:>gap$}- {field_color}{field}: {dim_reset}{value:?}",
+                        indent = "",
+                        field_color = self.2.field_dim(),
+                        dim_reset = self.2.dim_reset(),
                     ));
                 } else {
-                    drop(write!(self.0, " [36;2m{field}: [22m{value:?}"));
+                    drop(write!(
+                        self.0,
+                        " {field_color}{field}: {dim_reset}{value:?}",
+                        field_color = self.2.field_dim(),
+                        dim_reset = self.2.dim_reset(),
+                    ));
                 }
             }
         }
     }
 
-    if timestamp {
-        drop(write!(
-            out,
-            "[;2m[{timestamp}] ",
-            timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S"),
-        ));
+    if let Some(timer) = timer {
+        drop(write!(out, "{dim}[", dim = ansi.dim()));
+        drop(timer.write_timestamp(out));
+        drop(write!(out, "] "));
     }
 
     let depth = depth * 2;
     drop(write!(
         out,
-        "[m{indent:>depth$}{level}[m",
+        "{reset}{indent:>depth$}{level_color}{level}{reset}",
         indent = "",
+        reset = ansi.reset(),
+        level_color = ansi.level(*event.metadata().level()),
         level = match *event.metadata().level() {
-            tracing::Level::TRACE => {
-                "[94mTRACE"
-            }
-            tracing::Level::DEBUG => {
-                "[34mDEBUG"
-            }
-            tracing::Level::INFO => {
-                "[32mINFO"
-            }
-            tracing::Level::WARN => {
-                "[33mWARN"
-            }
-            tracing::Level::ERROR => {
-                "[31mERROR"
-            }
+            tracing::Level::TRACE => "TRACE",
+            tracing::Level::DEBUG => "DEBUG",
+            tracing::Level::INFO => "INFO",
+            tracing::Level::WARN => "WARN",
+            tracing::Level::ERROR => "ERROR",
         }
     ));
 
     event.record(&mut Messenger(out));
-    event.record(&mut Fielder(out, multiline.then_some(depth)));
-    drop(writeln!(out, "[m"));
+    event.record(&mut Fielder(out, multiline.then_some(depth), ansi));
+
+    print_location(
+        out,
+        &location_fields(event.metadata(), annotations),
+        depth,
+        multiline,
+        ansi,
+    );
+
+    drop(writeln!(out, "{reset}", reset = ansi.reset()));
+}
+
+fn print_span_timing(out: &mut impl std::io::Write, depth: usize, busy: u64, idle: u64, ansi: &Ansi) {
+    drop(writeln!(
+        out,
+        "{indent:>0$}{dim}{busy} busy, {idle} idle{reset}",
+        depth * 2,
+        indent = "",
+        dim = ansi.dim(),
+        reset = ansi.reset(),
+        busy = format_duration(busy),
+        idle = format_duration(idle),
+    ));
+}
+
+/// Formats a nanosecond duration like tracing-subscriber's `TimingDisplay`, picking the largest
+/// unit that keeps the value readable (e.g. `12.3ms`).
+fn format_duration(nanos: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let nanos = nanos as f64;
+
+    if nanos < 1_000.0 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000.0 {
+        format!("{:.1}µs", nanos / 1_000.0)
+    } else if nanos < 1_000_000_000.0 {
+        format!("{:.1}ms", nanos / 1_000_000.0)
+    } else {
+        format!("{:.2}s", nanos / 1_000_000_000.0)
+    }
+}
+
+fn span_to_json(
+    span: &tracing_subscriber::registry::SpanRef<'_, tracing_subscriber::Registry>,
+) -> Option<serde_json::Value> {
+    let extensions = span.extensions();
+    let info = extensions.get::<SpanInfo>()?;
+
+    let fields: serde_json::Map<String, serde_json::Value> = info
+        .records
+        .iter()
+        .filter(|(name, _)| *name != "message")
+        .map(|(name, value)| ((*name).to_owned(), serde_json::Value::String(value.clone())))
+        .collect();
+
+    Some(serde_json::json!({
+        "name": span.name(),
+        "target": span.metadata().target(),
+        "id": info.id,
+        "fields": fields,
+    }))
+}
+
+fn span_chain(
+    span: Option<&tracing_subscriber::registry::SpanRef<'_, tracing_subscriber::Registry>>,
+) -> Vec<serde_json::Value> {
+    let Some(span) = span else {
+        return Vec::new();
+    };
+
+    let mut chain = vec![span_to_json(span)];
+
+    let mut current = span.parent();
+    while let Some(span) = current {
+        chain.push(span_to_json(&span));
+        current = span.parent();
+    }
+
+    let mut chain: Vec<_> = chain.into_iter().flatten().collect();
+    chain.reverse();
+    chain
+}
+
+fn print_event_json(
+    out: &mut impl std::io::Write,
+    event: &tracing::Event<'_>,
+    span: Option<&tracing_subscriber::registry::SpanRef<'_, tracing_subscriber::Registry>>,
+    timer: Option<&dyn Timer>,
+) {
+    struct Fields {
+        message: Option<String>,
+        fields: serde_json::Map<String, serde_json::Value>,
+    }
+
+    impl tracing_subscriber::field::Visit for Fields {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.message = Some(format!("{value:?}"));
+            } else {
+                self.fields.insert(
+                    field.name().to_owned(),
+                    serde_json::Value::String(format!("{value:?}")),
+                );
+            }
+        }
+    }
+
+    let mut fields = Fields {
+        message: None,
+        fields: serde_json::Map::new(),
+    };
+    event.record(&mut fields);
+
+    let mut object = serde_json::Map::new();
+
+    if let Some(timer) = timer {
+        object.insert(
+            "timestamp".to_owned(),
+            serde_json::Value::String(render_timestamp(timer)),
+        );
+    }
+
+    object.insert(
+        "level".to_owned(),
+        serde_json::Value::String(event.metadata().level().to_string()),
+    );
+    object.insert(
+        "target".to_owned(),
+        serde_json::Value::String(event.metadata().target().to_owned()),
+    );
+    object.insert(
+        "message".to_owned(),
+        fields
+            .message
+            .map_or(serde_json::Value::Null, serde_json::Value::String),
+    );
+    object.insert("fields".to_owned(), serde_json::Value::Object(fields.fields));
+    object.insert("spans".to_owned(), serde_json::Value::Array(span_chain(span)));
+
+    drop(writeln!(out, "{}", serde_json::Value::Object(object)));
+}
+
+fn print_span_json(
+    out: &mut impl std::io::Write,
+    span: &tracing_subscriber::registry::SpanRef<'_, tracing_subscriber::Registry>,
+) {
+    let extensions = span.extensions();
+    let Some(info) = extensions.get::<SpanInfo>() else {
+        drop(writeln!(out, "{{\"error\":\"failed to read span info\"}}"));
+        return;
+    };
+
+    let mut object = serde_json::Map::new();
+
+    if let Some(date_time) = &info.date_time {
+        object.insert(
+            "timestamp".to_owned(),
+            serde_json::Value::String(date_time.clone()),
+        );
+    }
+
+    object.insert(
+        "level".to_owned(),
+        serde_json::Value::String(span.metadata().level().to_string()),
+    );
+    object.insert(
+        "target".to_owned(),
+        serde_json::Value::String(span.metadata().target().to_owned()),
+    );
+    object.insert(
+        "spans".to_owned(),
+        serde_json::Value::Array(span_chain(Some(span))),
+    );
+
+    drop(writeln!(out, "{}", serde_json::Value::Object(object)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// An [`Output`] backed by a shared buffer, so the test can inspect what was written after the
+    /// subscriber has taken ownership of it.
+    #[derive(Clone, Default)]
+    struct Sink(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl Sink {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Output for Sink {
+        fn lock(&self) -> impl std::io::Write {
+            SinkGuard(self.0.clone())
+        }
+    }
+
+    struct SinkGuard(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SinkGuard {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.0.lock().unwrap().flush()
+        }
+    }
+
+    #[test]
+    fn json_event_carries_the_nested_span_chain() {
+        let sink = Sink::default();
+        let layer = Layer::builder(sink.clone()).json().build();
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let outer = tracing::info_span!("outer", a = 1);
+            let _outer = outer.enter();
+            let inner = tracing::info_span!("inner", b = 2);
+            let _inner = inner.enter();
+            tracing::info!("hi");
+        });
+
+        let out = sink.contents();
+        let line = out.lines().next().expect("one JSON line per event");
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+
+        let spans = value["spans"].as_array().unwrap();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0]["name"], "outer");
+        assert_eq!(spans[1]["name"], "inner");
+    }
+
+    #[test]
+    fn span_timings_print_busy_and_idle_on_close() {
+        let sink = Sink::default();
+        let layer = Layer::builder(sink.clone()).span_timings().build();
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("work").in_scope(|| {});
+        });
+
+        let out = sink.contents();
+        assert!(out.contains("busy"));
+        assert!(out.contains("idle"));
+    }
+
+    #[test]
+    fn level_router_splits_output_by_level() {
+        let primary = Sink::default();
+        let fallback = Sink::default();
+        let router = crate::output::LevelRouter {
+            primary: primary.clone(),
+            min_level: tracing::Level::WARN,
+            fallback: fallback.clone(),
+        };
+        let layer = Layer::builder(router).build();
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("uh oh");
+            tracing::info!("just fyi");
+        });
+
+        assert!(primary.contents().contains("uh oh"));
+        assert!(!primary.contents().contains("just fyi"));
+        assert!(fallback.contents().contains("just fyi"));
+        assert!(!fallback.contents().contains("uh oh"));
+    }
+
+    #[test]
+    fn on_record_merges_fields_recorded_after_creation() {
+        let sink = Sink::default();
+        let layer = Layer::builder(sink.clone()).no_color().build();
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("request", request_id = tracing::field::Empty);
+            span.record("request_id", "abc123");
+            let _enter = span.enter();
+            tracing::info!("inside");
+        });
+
+        assert!(sink.contents().contains("request_id: \"abc123\""));
+    }
 }
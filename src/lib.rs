@@ -10,25 +10,47 @@
 //!
 //! Spans are normally not printed unless an event occurs within them. This behavior can be
 //! changed so that they are always printed when entered by calling
-//! [`log_spans`](builder::Builder::log_spans).
+//! [`log_spans`](layer::Builder::log_spans).
 //!
 //! Fields of spans and events are printed inline, so that each line is a log entry. However,
-//! setting [`multiline`](builder::Builder::multiline) prints each field in a separate line
+//! setting [`multiline`](layer::Builder::multiline) prints each field in a separate line
+//!
+//! Instead of the ANSI tree, events can be emitted as one JSON object each, carrying their full
+//! owning span hierarchy, by calling [`json`](layer::Builder::json).
+//!
+//! ANSI colors are printed by default, but are automatically suppressed when the output is not a
+//! terminal, or can be disabled unconditionally with [`no_color`](layer::Builder::no_color).
+//!
+//! Calling [`span_timings`](layer::Builder::span_timings) prints the busy/idle time accounting
+//! of a span when it closes.
+//!
+//! The timestamp defaults to UTC, but any [`Timer`](timer::Timer) can be plugged in with
+//! [`with_timer`](layer::Builder::with_timer), e.g. to log local time, RFC 3339, or process
+//! uptime.
+//!
+//! The [`Output`] a span or event is written to can depend on its level, e.g. to split
+//! `WARN`/`ERROR` off to stderr, by implementing [`Output::lock_for`] or composing
+//! [`output::LevelRouter`]. [`output::Tee`] duplicates every entry across two outputs.
+//!
+//! The source file, line number, thread id and thread name of each event can be appended as dim
+//! trailing annotations by calling [`with_file`](layer::Builder::with_file),
+//! [`with_line_number`](layer::Builder::with_line_number),
+//! [`with_thread_ids`](layer::Builder::with_thread_ids) and
+//! [`with_thread_names`](layer::Builder::with_thread_names).
 //!
 //! # Examples
 //!
 //! ```
-//! # use treetrace::{builder::Builder, output::Stdout};
+//! # use treetrace::output::Stdout;
 //! # use tracing_subscriber::layer::SubscriberExt;
-//! let layer = Builder::new(Stdout).build();
+//! let layer = treetrace::Layer::builder(Stdout).build();
 //! let subscriber = tracing_subscriber::registry().with(layer);
 //! tracing::subscriber::set_global_default(subscriber).unwrap();
 //! ```
 
-pub mod builder;
 pub mod layer;
 pub mod output;
+pub mod timer;
 
-pub use builder::Builder;
 pub use layer::Layer;
 pub use output::{Output, Stderr, Stdout};
@@ -1,6 +1,26 @@
 /// A trait that allows locking access to a [`Write`](std::io::Write)
 pub trait Output: Send + Sync + 'static {
     fn lock(&self) -> impl std::io::Write;
+
+    /// Locks access to a [`Write`](std::io::Write), allowing the choice of writer to depend on
+    /// the [`Metadata`](tracing::Metadata) of the span or event being printed.
+    ///
+    /// Defaults to ignoring `meta` and deferring to [`lock`](Self::lock). Implementations such as
+    /// [`LevelRouter`] override this to send, e.g., `WARN`/`ERROR` to a different sink than the
+    /// rest.
+    fn lock_for(&self, meta: &tracing::Metadata<'_>) -> impl std::io::Write {
+        let _ = meta;
+        self.lock()
+    }
+
+    /// Whether this output is attached to a terminal.
+    ///
+    /// Used to decide whether ANSI colors should be emitted by default when the builder has not
+    /// explicitly been told otherwise. Defaults to `true`, preserving colored output for
+    /// implementations that cannot tell.
+    fn is_terminal(&self) -> bool {
+        true
+    }
 }
 
 /// And implementation of [`Output`] that uses [`stdout`](std::io::Stdout)
@@ -11,6 +31,10 @@ impl Output for Stdout {
     fn lock(&self) -> impl std::io::Write {
         std::io::stdout().lock()
     }
+
+    fn is_terminal(&self) -> bool {
+        std::io::IsTerminal::is_terminal(&std::io::stdout())
+    }
 }
 
 /// And implementation of [`Output`] that uses [`stderr`](std::io::Stderr)
@@ -21,6 +45,10 @@ impl Output for Stderr {
     fn lock(&self) -> impl std::io::Write {
         std::io::stderr().lock()
     }
+
+    fn is_terminal(&self) -> bool {
+        std::io::IsTerminal::is_terminal(&std::io::stderr())
+    }
 }
 
 /// And implementation of [`Output`] that uses any [`Write`](std::io::Write)
@@ -29,8 +57,8 @@ impl Output for Stderr {
 ///
 /// ```
 /// # use treetrace::output::Memory;
-/// # use treetrace::builder::Builder;
-/// Builder::new(Memory::new(Vec::<u8>::new()));
+/// # use treetrace::Layer;
+/// Layer::builder(Memory::new(Vec::<u8>::new()));
 /// ```
 #[derive(Debug)]
 pub struct Memory<T>(std::sync::Mutex<T>);
@@ -42,7 +70,6 @@ impl<T: 'static + Send + std::io::Write> Memory<T> {
     ///
     /// ```
     /// # use treetrace::output::Memory;
-    /// # use treetrace::builder::Builder;
     /// Memory::new(Vec::<u8>::new());
     /// ```
     pub fn new(buffer: T) -> Self {
@@ -79,3 +106,106 @@ impl<T: 'static + Send + std::io::Write> std::io::Write for MemoryGuard<'_, T> {
         self.0.write_fmt(fmt)
     }
 }
+
+/// An implementation of [`Output`] that writes every entry to both `A` and `B`.
+///
+/// # Examples
+///
+/// ```
+/// # use treetrace::output::{Tee, Stdout, Stderr};
+/// Tee(Stdout, Stderr);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Tee<A, B>(pub A, pub B);
+
+impl<A: Output, B: Output> Output for Tee<A, B> {
+    fn lock(&self) -> impl std::io::Write {
+        TeeGuard(self.0.lock(), self.1.lock())
+    }
+
+    fn lock_for(&self, meta: &tracing::Metadata<'_>) -> impl std::io::Write {
+        TeeGuard(self.0.lock_for(meta), self.1.lock_for(meta))
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.0.is_terminal() && self.1.is_terminal()
+    }
+}
+
+struct TeeGuard<A, B>(A, B);
+
+impl<A: std::io::Write, B: std::io::Write> std::io::Write for TeeGuard<A, B> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write_all(buf)?;
+        self.1.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()?;
+        self.1.flush()
+    }
+}
+
+/// An implementation of [`Output`] that routes writes to `primary` when the level is at least as
+/// severe as `min_level`, falling back to `fallback` otherwise.
+///
+/// This is the common pattern of sending `WARN`/`ERROR` to stderr while everything else goes to
+/// stdout.
+///
+/// # Examples
+///
+/// ```
+/// # use treetrace::output::{LevelRouter, Stdout, Stderr};
+/// # use tracing::Level;
+/// LevelRouter {
+///     primary: Stderr,
+///     min_level: Level::WARN,
+///     fallback: Stdout,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LevelRouter<P, F> {
+    pub primary: P,
+    pub min_level: tracing::Level,
+    pub fallback: F,
+}
+
+impl<P: Output, F: Output> Output for LevelRouter<P, F> {
+    fn lock(&self) -> impl std::io::Write {
+        RouterGuard::<_, F>::Primary(self.primary.lock())
+    }
+
+    fn lock_for(&self, meta: &tracing::Metadata<'_>) -> impl std::io::Write {
+        if *meta.level() <= self.min_level {
+            RouterGuard::Primary(self.primary.lock_for(meta))
+        } else {
+            RouterGuard::Fallback(self.fallback.lock_for(meta))
+        }
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.primary.is_terminal() && self.fallback.is_terminal()
+    }
+}
+
+enum RouterGuard<P, F> {
+    Primary(P),
+    Fallback(F),
+}
+
+impl<P: std::io::Write, F: std::io::Write> std::io::Write for RouterGuard<P, F> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Primary(w) => w.write(buf),
+            Self::Fallback(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Primary(w) => w.flush(),
+            Self::Fallback(w) => w.flush(),
+        }
+    }
+}
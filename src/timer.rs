@@ -0,0 +1,72 @@
+/// Writes a timestamp to a [`Write`](std::io::Write) sink.
+///
+/// Implementations decide both the clock and the rendering, letting a [`Layer`](crate::Layer) log
+/// in UTC, local time, process uptime, or any other scheme a consumer needs. See [`Utc`],
+/// [`Local`], [`Uptime`] and [`Rfc3339`] for the timers provided by this crate.
+pub trait Timer: Send + Sync + 'static {
+    /// Writes the current timestamp to `out`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    fn write_timestamp(&self, out: &mut dyn std::io::Write) -> std::io::Result<()>;
+}
+
+/// Prints the current time in UTC as `%Y-%m-%d %H:%M:%S`.
+///
+/// This is the default timer used by [`Layer::builder`](crate::Layer::builder).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct Utc;
+
+impl Timer for Utc {
+    fn write_timestamp(&self, out: &mut dyn std::io::Write) -> std::io::Result<()> {
+        write!(out, "{}", chrono::Utc::now().format("%Y-%m-%d %H:%M:%S"))
+    }
+}
+
+/// Prints the current time in the system's local timezone as `%Y-%m-%d %H:%M:%S`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct Local;
+
+impl Timer for Local {
+    fn write_timestamp(&self, out: &mut dyn std::io::Write) -> std::io::Result<()> {
+        write!(out, "{}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"))
+    }
+}
+
+/// Prints the current time in UTC using RFC 3339, e.g. `2024-05-01T12:34:56.789012345+00:00`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct Rfc3339;
+
+impl Timer for Rfc3339 {
+    fn write_timestamp(&self, out: &mut dyn std::io::Write) -> std::io::Result<()> {
+        write!(out, "{}", chrono::Utc::now().to_rfc3339())
+    }
+}
+
+/// Prints the time elapsed since this timer was created, e.g. `12.345678s`.
+///
+/// Useful for debugging relative timing without the noise of wall-clock dates, similarly to
+/// `tracing-subscriber`'s `Uptime`.
+#[derive(Debug, Clone)]
+pub struct Uptime(std::time::Instant);
+
+impl Uptime {
+    /// Creates a new [`Uptime`] timer, starting the clock now.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(std::time::Instant::now())
+    }
+}
+
+impl Default for Uptime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timer for Uptime {
+    fn write_timestamp(&self, out: &mut dyn std::io::Write) -> std::io::Result<()> {
+        write!(out, "{:.6}s", self.0.elapsed().as_secs_f64())
+    }
+}